@@ -16,6 +16,7 @@
 
 from fireflyframework_agentic.agents.base import FireflyAgent
 from fireflyframework_agentic.agents.builtin_middleware import (
+    AuditMiddleware,
     BudgetExceededError,
     CacheMiddleware,
     CostGuardMiddleware,
@@ -64,6 +65,7 @@ __all__ = [
     "agent_lifecycle",
     "AgentMiddleware",
     "AgentRegistry",
+    "AuditMiddleware",
     "BudgetExceededError",
     "CacheMiddleware",
     "CacheStatistics",
@@ -246,6 +246,7 @@ class FireflyAgent(Generic[AgentDepsT, OutputT]):
 
         mw_ctx = MiddlewareContext(
             agent_name=self._name,
+            model=self._model_identifier,
             prompt=prompt,
             method="run",
             deps=deps,
@@ -291,6 +292,7 @@ class FireflyAgent(Generic[AgentDepsT, OutputT]):
 
         mw_ctx = MiddlewareContext(
             agent_name=self._name,
+            model=self._model_identifier,
             prompt=prompt,
             method="run_sync",
             deps=deps,
@@ -350,6 +352,7 @@ class FireflyAgent(Generic[AgentDepsT, OutputT]):
 
         mw_ctx = MiddlewareContext(
             agent_name=self._name,
+            model=self._model_identifier,
             prompt=prompt,
             method="run_stream",
             deps=deps,
@@ -502,6 +505,7 @@ class FireflyAgent(Generic[AgentDepsT, OutputT]):
 
         mw_ctx = MiddlewareContext(
             agent_name=self._name,
+            model=self._model_identifier,
             prompt=prompt,
             method="run_with_reasoning",
             deps=None,
@@ -761,6 +765,8 @@ class _UsageTrackingStreamContext:
             )
         # Run after-hooks on the stream handle so middleware can inspect usage.
         if self._mw_ctx is not None:
+            if exc_val is not None:
+                self._mw_ctx.metadata["_stream_error"] = exc_val
             await self._agent._middleware.run_after(self._mw_ctx, self._stream)
         return result
 
@@ -818,6 +824,8 @@ class _IncrementalStreamContext:
             )
         # Run after-hooks on the stream handle so middleware can inspect usage.
         if self._mw_ctx is not None:
+            if exc_val is not None:
+                self._mw_ctx.metadata["_stream_error"] = exc_val
             await self._agent._middleware.run_after(self._mw_ctx, self._stream)
         return result
 
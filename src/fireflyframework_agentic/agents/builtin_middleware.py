@@ -20,6 +20,7 @@ The framework ships ready-to-use middleware classes:
   (auto-wired by default).
 * :class:`PromptGuardMiddleware` -- prompt-injection detection/sanitisation.
 * :class:`CostGuardMiddleware` -- budget enforcement before an LLM call.
+* :class:`AuditMiddleware` -- hash-chained audit entries for every run.
 * :class:`RetryMiddleware` -- automatic retry with backoff on rate limit errors.
 
 Usage::
@@ -409,6 +410,71 @@ class ExplainabilityMiddleware:
         return result
 
 
+# -- AuditMiddleware ---------------------------------------------------------
+
+
+class AuditMiddleware:
+    """Appends a hash-chained audit entry after each agent run.
+
+    Each entry records the agent, its model, token usage, latency, and
+    SHA-256 digests of the prompt and output (the content itself is not
+    stored) in an
+    :class:`~fireflyframework_agentic.explainability.audit.AuditTrail`.
+
+    Only runs that reach ``after_run`` are recorded: successful calls, and
+    streams, which are recorded with ``outcome="failure"`` when the stream
+    exits with an exception.  Calls that raise before returning are not
+    recorded.
+
+    Like :class:`ExplainabilityMiddleware`, this middleware is **not**
+    auto-wired.  Enable it explicitly via the agent's ``middleware`` list.
+
+    Parameters:
+        trail: The :class:`AuditTrail` to append to, typically one backed
+            by a JSONL file.
+    """
+
+    def __init__(self, *, trail: Any) -> None:
+        self._trail = trail
+
+    async def before_run(self, context: MiddlewareContext) -> None:
+        """Start the latency clock."""
+        context.metadata["_audit_t0"] = time.monotonic()
+
+    async def after_run(self, context: MiddlewareContext, result: Any) -> Any:
+        """Append an ``llm_call`` entry describing the run."""
+        from fireflyframework_agentic.explainability.audit import hash_content
+
+        t0 = context.metadata.get("_audit_t0")
+        usage = result.usage() if callable(getattr(result, "usage", None)) else None
+        input_tokens = getattr(usage, "input_tokens", 0) or 0
+        output_tokens = getattr(usage, "output_tokens", 0) or 0
+        try:
+            output = str(result.output)
+        except Exception:  # stream handles may not expose a final output
+            output = ""
+        error = context.metadata.get("_stream_error")
+        self._trail.append(
+            actor=context.agent_name,
+            action="llm_call",
+            resource=context.model,
+            outcome="failure" if error is not None else "success",
+            detail={
+                "method": context.method,
+                "model": context.model,
+                "input_tokens": input_tokens,
+                "output_tokens": output_tokens,
+                "total_tokens": getattr(usage, "total_tokens", 0) or (input_tokens + output_tokens),
+                "latency_ms": round((time.monotonic() - t0) * 1000, 1) if t0 is not None else None,
+                "cached": "_cache_result" in context.metadata,
+                "prompt_sha256": hash_content(str(context.prompt) if context.prompt is not None else ""),
+                "output_sha256": hash_content(output),
+                **({"error": type(error).__name__} if error is not None else {}),
+            },
+        )
+        return result
+
+
 # -- CacheMiddleware ---------------------------------------------------------
 
 
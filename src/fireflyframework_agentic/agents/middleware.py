@@ -50,6 +50,7 @@ class MiddlewareContext:
         deps: The dependencies object.
         kwargs: Extra keyword arguments passed to ``run()``.
         metadata: Arbitrary dict for middleware to share state across hooks.
+        model: Identifier of the agent's model (e.g. ``"openai:gpt-4o"``).
     """
 
     agent_name: str
@@ -59,6 +60,7 @@ class MiddlewareContext:
     kwargs: dict[str, Any] = field(default_factory=dict)
     metadata: dict[str, Any] = field(default_factory=dict)
     context: Any = None
+    model: str = ""
 
 
 @runtime_checkable
@@ -14,7 +14,7 @@
 
 """Explainability subpackage -- trace recording, explanations, audit, and reports."""
 
-from fireflyframework_agentic.explainability.audit import AuditEntry, AuditTrail
+from fireflyframework_agentic.explainability.audit import AuditEntry, AuditTrail, hash_content
 from fireflyframework_agentic.explainability.explanation import ExplanationGenerator
 from fireflyframework_agentic.explainability.report import (
     ExplainabilityReport,
@@ -37,4 +37,5 @@ __all__ = [
     "ReportSection",
     "TraceRecorder",
     "default_trace_recorder",
+    "hash_content",
 ]
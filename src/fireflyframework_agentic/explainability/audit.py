@@ -12,40 +12,119 @@
 # See the License for the specific language governing permissions and
 # limitations under the License.
 
-"""Immutable audit trail for regulatory compliance.
-
-:class:`AuditTrail` stores :class:`AuditEntry` records that cannot be
-modified after creation.  Entries can be exported to JSON for archival.
+"""Tamper-evident audit trail for regulatory compliance.
+
+:class:`AuditTrail` stores :class:`AuditEntry` records in an append-only,
+hash-chained log: every entry carries the SHA-256 hash of the previous
+entry, so modifying, removing, or reordering an entry is detected by
+:meth:`AuditTrail.verify`.  Dropping entries from the *end* of the log
+leaves a valid shorter chain; keep :attr:`AuditTrail.head_hash` somewhere
+else and pass it to :meth:`~AuditTrail.verify` to detect that as well.
+The trail can optionally be persisted as a JSONL file and exported to
+JSON, JSONL, or CSV for archival.
 """
 
 from __future__ import annotations
 
+import csv
+import hashlib
+import io
 import json
+import logging
 from datetime import UTC, datetime
+from pathlib import Path
 from typing import Any
 
-from pydantic import BaseModel, Field
+from pydantic import BaseModel, ConfigDict, Field
+
+logger = logging.getLogger(__name__)
+
+
+def hash_content(text: str) -> str:
+    """Return the SHA-256 hex digest of *text*.
+
+    Useful for recording prompts and responses in an audit entry's
+    ``detail`` without storing the content itself.
+    """
+    return hashlib.sha256(text.encode("utf-8")).hexdigest()
 
 
 class AuditEntry(BaseModel):
     """A single immutable audit record."""
 
+    model_config = ConfigDict(frozen=True)
+
     timestamp: datetime = Field(default_factory=lambda: datetime.now(UTC))
     actor: str  # agent name, user id, or system
     action: str  # "llm_call", "tool_execution", "delegation", etc.
     resource: str = ""  # what was acted upon
     detail: dict[str, Any] = {}
     outcome: str = "success"  # "success" or "failure"
+    previous_hash: str = ""  # hash of the preceding entry ("" for the first)
+    hash: str = ""
 
+    def compute_hash(self) -> str:
+        """Return the SHA-256 hash of this entry's content and chain link."""
+        # Hash the persisted (JSON) form so a reloaded entry hashes the same,
+        # e.g. NaN/inf are serialised as null.
+        payload = json.loads(self.model_dump_json(exclude={"hash"}))
+        canonical = json.dumps(payload, sort_keys=True, separators=(",", ":"))
+        return hashlib.sha256(canonical.encode("utf-8")).hexdigest()
 
-class AuditTrail:
-    """Append-only audit log.
 
-    Entries cannot be removed or modified after they are appended.
+class AuditTrail:
+    """Append-only, hash-chained audit log.
+
+    Entries are frozen and :attr:`entries` / :meth:`append` hand out
+    copies, so nothing obtained through this API can alter the log;
+    tampering with the underlying data is detected by :meth:`verify`.
+
+    ``detail`` values that are not JSON-native (datetimes, custom objects,
+    ...) are stored as their ``str()`` form so that every entry can be
+    hashed, persisted, and exported.
+
+    Parameters:
+        path: Optional JSONL file backing the trail.  Existing entries are
+            loaded on construction and every new entry is appended to the
+            file as a single line.  An incomplete last line (a write cut
+            short by a crash) is logged and ignored; any other unreadable
+            line raises :class:`ValueError`.  Loading never modifies the
+            file: the incomplete line is truncated away (or a missing final
+            newline added) only when :meth:`append` is first called.
     """
 
-    def __init__(self) -> None:
+    def __init__(self, path: str | Path | None = None) -> None:
         self._entries: list[AuditEntry] = []
+        self._path = Path(path) if path is not None else None
+        # Pending repair of the backing file, applied by the first append():
+        # truncate an incomplete last line, or terminate a valid one.
+        self._truncate_to: int | None = None
+        self._missing_newline = False
+        if self._path is not None and self._path.exists():
+            self._load(self._path)
+
+    def _load(self, path: Path) -> None:
+        data = path.read_bytes()
+        self._missing_newline = bool(data) and not data.endswith(b"\n")
+        offset = 0
+        for lineno, raw in enumerate(data.split(b"\n"), start=1):
+            end = offset + len(raw)
+            if raw.strip():
+                try:
+                    self._entries.append(AuditEntry.model_validate_json(raw))
+                except ValueError as exc:
+                    if end == len(data):  # unterminated final line
+                        logger.warning(
+                            "Audit log %s: ignoring incomplete last line %d (%d bytes)",
+                            path,
+                            lineno,
+                            len(raw),
+                        )
+                        self._truncate_to = offset
+                        self._missing_newline = False
+                        return
+                    raise ValueError(f"Audit log {path}: unreadable entry on line {lineno}") from exc
+            offset = end + 1
 
     def append(
         self,
@@ -61,16 +140,49 @@ class AuditTrail:
             actor=actor,
             action=action,
             resource=resource,
-            detail=detail or {},
+            detail=json.loads(json.dumps(detail or {}, default=str)),
             outcome=outcome,
+            previous_hash=self.head_hash,
         )
+        entry = entry.model_copy(update={"hash": entry.compute_hash()})
         self._entries.append(entry)
-        return entry
+        if self._path is not None:
+            self._path.parent.mkdir(parents=True, exist_ok=True)
+            if self._truncate_to is not None:
+                with self._path.open("r+b") as fh:
+                    fh.truncate(self._truncate_to)
+                self._truncate_to = None
+            with self._path.open("a", encoding="utf-8") as fh:
+                if self._missing_newline:
+                    fh.write("\n")
+                    self._missing_newline = False
+                fh.write(entry.model_dump_json() + "\n")
+        return entry.model_copy(deep=True)
+
+    @property
+    def head_hash(self) -> str:
+        """Hash of the most recent entry (``""`` for an empty trail)."""
+        return self._entries[-1].hash if self._entries else ""
+
+    def verify(self, expected_head: str | None = None) -> bool:
+        """Return *True* if every entry's hash and chain link are intact.
+
+        Parameters:
+            expected_head: A previously recorded :attr:`head_hash`.  When
+                given, the trail must also end at that entry, which
+                detects entries removed from the end of the log.
+        """
+        previous = ""
+        for entry in self._entries:
+            if entry.previous_hash != previous or entry.hash != entry.compute_hash():
+                return False
+            previous = entry.hash
+        return expected_head is None or previous == expected_head
 
     @property
     def entries(self) -> list[AuditEntry]:
-        """All audit entries (read-only copy)."""
-        return list(self._entries)
+        """All audit entries (copies; mutating them does not affect the trail)."""
+        return [e.model_copy(deep=True) for e in self._entries]
 
     def export_json(self) -> str:
         """Serialise the entire audit trail to a JSON string."""
@@ -80,5 +192,21 @@ class AuditTrail:
             default=str,
         )
 
+    def export_jsonl(self) -> str:
+        """Serialise the audit trail as JSON Lines (one entry per line)."""
+        return "".join(e.model_dump_json() + "\n" for e in self._entries)
+
+    def export_csv(self) -> str:
+        """Serialise the audit trail as CSV; ``detail`` is JSON-encoded."""
+        buf = io.StringIO()
+        fields = list(AuditEntry.model_fields)
+        writer = csv.DictWriter(buf, fieldnames=fields)
+        writer.writeheader()
+        for e in self._entries:
+            row = e.model_dump(mode="json")
+            row["detail"] = json.dumps(row["detail"], sort_keys=True, default=str)
+            writer.writerow(row)
+        return buf.getvalue()
+
     def __len__(self) -> int:
         return len(self._entries)
@@ -12,12 +12,13 @@
 # See the License for the specific language governing permissions and
 # limitations under the License.
 
-"""Lab subpackage -- sessions, benchmarks, comparisons, datasets, and evaluation."""
+"""Lab subpackage -- sessions, benchmarks, comparisons, datasets, evaluation, and report publishing."""
 
 from fireflyframework_agentic.lab.benchmark import Benchmark, BenchmarkResult
 from fireflyframework_agentic.lab.comparison import ComparisonEntry, ModelComparison
 from fireflyframework_agentic.lab.dataset import EvalCase, EvalDataset
 from fireflyframework_agentic.lab.evaluator import EvalOrchestrator, EvalReport, EvalResult
+from fireflyframework_agentic.lab.publishing import EvalReportPublisher
 from fireflyframework_agentic.lab.session import LabSession, SessionEntry
 
 __all__ = [
@@ -28,6 +29,7 @@ __all__ = [
     "EvalDataset",
     "EvalOrchestrator",
     "EvalReport",
+    "EvalReportPublisher",
     "EvalResult",
     "LabSession",
     "ModelComparison",
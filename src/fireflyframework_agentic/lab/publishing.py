@@ -0,0 +1,180 @@
+# Copyright 2026 Firefly Software Solutions Inc
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+"""Badge and static HTML publishing for evaluation reports.
+
+:class:`EvalReportPublisher` turns an :class:`EvalReport` into a
+shields.io endpoint badge (JSON) and a standalone HTML report, writes
+both to a directory, or POSTs them to a configured endpoint so the
+latest eval status can be embedded in a README.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import html
+import json
+import urllib.error
+import urllib.request
+from datetime import UTC, datetime
+from pathlib import Path
+from typing import Any
+
+from fireflyframework_agentic.lab.evaluator import EvalReport
+
+# (minimum pass rate, shields colour), checked top to bottom
+_DEFAULT_COLORS: list[tuple[float, str]] = [
+    (0.9, "brightgreen"),
+    (0.75, "green"),
+    (0.5, "yellow"),
+    (0.0, "red"),
+]
+
+
+class EvalReportPublisher:
+    """Render and publish :class:`EvalReport` results.
+
+    Parameters:
+        label: Left-hand text of the badge.
+        pass_threshold: Minimum score for a case to count as passed.
+        colors: ``(min_pass_rate, colour)`` pairs, in any order; the
+            highest threshold the pass rate reaches wins.
+    """
+
+    def __init__(
+        self,
+        *,
+        label: str = "eval",
+        pass_threshold: float = 0.5,
+        colors: list[tuple[float, str]] | None = None,
+    ) -> None:
+        self._label = label
+        self._pass_threshold = pass_threshold
+        self._colors = sorted(_DEFAULT_COLORS if colors is None else colors, reverse=True)
+
+    def pass_rate(self, report: EvalReport) -> float:
+        """Fraction of cases whose score reaches the pass threshold."""
+        if not report.results:
+            return 0.0
+        return self._passed(report) / len(report.results)
+
+    def _passed(self, report: EvalReport) -> int:
+        return sum(1 for r in report.results if r.score >= self._pass_threshold)
+
+    def _pass_percent(self, report: EvalReport) -> str:
+        # Rounded down so a single failure never shows as "100%"
+        if not report.results:
+            return "0%"
+        return f"{self._passed(report) * 100 // len(report.results)}%"
+
+    def badge(self, report: EvalReport) -> dict[str, Any]:
+        """Return a shields.io endpoint badge payload for *report*."""
+        if not report.results:
+            return {"schemaVersion": 1, "label": self._label, "message": "no data", "color": "lightgrey"}
+        rate = self.pass_rate(report)
+        color = next((c for threshold, c in self._colors if rate >= threshold), "red")
+        return {
+            "schemaVersion": 1,
+            "label": self._label,
+            "message": f"{self._pass_percent(report)} pass",
+            "color": color,
+        }
+
+    def render_html(self, report: EvalReport, *, title: str = "") -> str:
+        """Render *report* as a standalone HTML page."""
+        heading = html.escape(title or f"Evaluation report: {report.agent_name}")
+        generated = datetime.now(UTC).strftime("%Y-%m-%d %H:%M:%S UTC")
+        rows = "\n".join(
+            f'<tr class="{"pass" if r.score >= self._pass_threshold else "fail"}">'
+            f"<td>{html.escape(r.input)}</td><td>{html.escape(r.expected)}</td>"
+            f"<td>{html.escape(r.actual)}</td><td>{r.score:.2f}</td></tr>"
+            for r in report.results
+        )
+        return f"""<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{heading}</title>
+<style>
+body {{ font-family: sans-serif; margin: 2rem; }}
+table {{ border-collapse: collapse; width: 100%; }}
+th, td {{ border: 1px solid #ddd; padding: 0.4rem; text-align: left; vertical-align: top; }}
+tr.pass td:last-child {{ color: #2e7d32; }}
+tr.fail td:last-child {{ color: #c62828; }}
+</style>
+</head>
+<body>
+<h1>{heading}</h1>
+<p>Generated: {generated}</p>
+<p>Cases: {report.total_cases} &middot; Average score: {report.avg_score:.2f} &middot;
+Pass rate: {self._pass_percent(report)}</p>
+<table>
+<tr><th>Input</th><th>Expected</th><th>Actual</th><th>Score</th></tr>
+{rows}
+</table>
+</body>
+</html>
+"""
+
+    def write(self, report: EvalReport, directory: str | Path, *, title: str = "") -> tuple[Path, Path]:
+        """Write ``badge.json`` and ``report.html`` into *directory*.
+
+        Returns:
+            The paths of the badge and HTML files.
+        """
+        out = Path(directory)
+        out.mkdir(parents=True, exist_ok=True)
+        badge_path = out / "badge.json"
+        html_path = out / "report.html"
+        badge_path.write_text(json.dumps(self.badge(report), indent=2), encoding="utf-8")
+        html_path.write_text(self.render_html(report, title=title), encoding="utf-8")
+        return badge_path, html_path
+
+    async def publish(
+        self,
+        report: EvalReport,
+        endpoint: str,
+        *,
+        headers: dict[str, str] | None = None,
+        timeout: float = 30.0,
+        title: str = "",
+    ) -> int:
+        """POST the badge, HTML report, and raw results to *endpoint*.
+
+        Returns:
+            The HTTP status code returned by the endpoint, including
+            non-2xx codes.  Connection failures still raise
+            :class:`urllib.error.URLError`.
+        """
+        payload = {
+            "badge": self.badge(report),
+            "html": self.render_html(report, title=title),
+            "report": report.model_dump(mode="json"),
+        }
+        data = json.dumps(payload).encode("utf-8")
+        req = urllib.request.Request(
+            endpoint,
+            data=data,
+            headers={"Content-Type": "application/json", **(headers or {})},
+            method="POST",
+        )
+
+        def _send() -> int:
+            try:
+                with urllib.request.urlopen(req, timeout=timeout) as resp:
+                    return resp.status
+            except urllib.error.HTTPError as exc:
+                return exc.code
+
+        return await asyncio.to_thread(_send)
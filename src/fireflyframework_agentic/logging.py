@@ -31,6 +31,10 @@ Or even shorter::
     from fireflyframework_agentic.logging import enable_debug
 
     enable_debug()   # equivalent to configure_logging("DEBUG")
+
+To keep emails, credentials, and card numbers out of log files::
+
+    configure_logging("INFO", redact=True)
 """
 
 from __future__ import annotations
@@ -38,7 +42,9 @@ from __future__ import annotations
 import copy
 import json
 import logging
+import re
 import sys
+from collections.abc import Callable, Sequence
 from datetime import UTC, datetime
 from typing import Any
 
@@ -73,6 +79,116 @@ _SYMBOL_COLORS: dict[str, str] = {
 }
 
 
+# OutputGuard patterns (security/output_guard.py) that RedactionFilter
+# masks by default.  Deliberately narrower than OutputGuard's PII set:
+# phone/IP patterns would also match ids, ports, and timings that are
+# routine in log lines.
+_SHARED_PATTERN_NAMES: tuple[str, ...] = (
+    "email",
+    "credit_card",
+    "private_key",
+    "bearer_token",
+    "aws_access_key",
+    "github_token",
+)
+
+# Log-specific patterns masked on top of the shared ones.
+_LOG_PATTERNS: dict[str, str] = {
+    "provider_key": r"\bsk-(?:ant-|proj-)?[A-Za-z0-9_\-]{20,}",
+    "assigned_secret": r"(?i)\b(?:api[_-]?key|secret|token|password)\s*[:=]\s*['\"]?[^\s'\",]{8,}",
+}
+
+
+def default_redaction_patterns() -> dict[str, str]:
+    """Return the ``{name: regex}`` patterns :class:`RedactionFilter` uses by default."""
+    from fireflyframework_agentic.security.output_guard import _PII_PATTERNS, _SECRETS_PATTERNS
+
+    shared = {**_PII_PATTERNS, **_SECRETS_PATTERNS}
+    return {**{name: shared[name] for name in _SHARED_PATTERN_NAMES}, **_LOG_PATTERNS}
+
+
+def _luhn_valid(text: str) -> bool:
+    """Return *True* if the digits in *text* pass the Luhn checksum."""
+    digits = [int(c) for c in text if c.isdigit()]
+    total = 0
+    for i, d in enumerate(reversed(digits)):
+        if i % 2:
+            d *= 2
+            if d > 9:
+                d -= 9
+        total += d
+    return total % 10 == 0
+
+
+# Extra checks a match must pass before it is masked, keyed by pattern
+# name.  Keeps 16-digit trace ids and the like out of the card pattern.
+_MATCH_VALIDATORS: dict[str, Callable[[str], bool]] = {
+    "credit_card": _luhn_valid,
+}
+
+
+class RedactionFilter(logging.Filter):
+    """Mask PII and credentials in log records before they are emitted.
+
+    Attach to a handler (or pass ``redact=True`` to
+    :func:`configure_logging`) so every record reaching it is scrubbed,
+    including records from child loggers.  The record is copied, so other
+    handlers see the original unless they are filtered too.
+
+    Parameters:
+        patterns: ``{name: regex}`` patterns to mask.  Defaults to
+            :func:`default_redaction_patterns`: emails, Luhn-valid card
+            numbers, PEM private keys, and common API key / token formats.
+        custom_patterns: Additional ``{name: regex}`` patterns.
+        terms: Literal strings (customer names, project codes, ...) to mask.
+        replacement: Text substituted for every match.
+    """
+
+    def __init__(
+        self,
+        *,
+        patterns: dict[str, str] | None = None,
+        custom_patterns: dict[str, str] | None = None,
+        terms: Sequence[str] = (),
+        replacement: str = "[REDACTED]",
+    ) -> None:
+        super().__init__()
+        raw = default_redaction_patterns() if patterns is None else dict(patterns)
+        raw.update(custom_patterns or {})
+        self._rules: list[tuple[re.Pattern[str], str | Callable[[re.Match[str]], str]]] = []
+        for name, pattern in raw.items():
+            validator = _MATCH_VALIDATORS.get(name)
+            repl = self._checked(validator, replacement) if validator else replacement
+            self._rules.append((re.compile(pattern), repl))
+        self._rules.extend((re.compile(re.escape(t)), replacement) for t in terms if t)
+
+    @staticmethod
+    def _checked(validator: Callable[[str], bool], replacement: str) -> Callable[[re.Match[str]], str]:
+        return lambda m: replacement if validator(m.group()) else m.group()
+
+    def redact(self, text: str) -> str:
+        """Return *text* with every configured pattern masked."""
+        for compiled, replacement in self._rules:
+            text = compiled.sub(replacement, text)
+        return text
+
+    def filter(self, record: logging.LogRecord) -> logging.LogRecord:
+        record = copy.copy(record)
+        try:
+            record.msg = self.redact(record.getMessage())
+            record.args = None
+        except Exception:
+            # Bad format args: leave them for Handler.handleError to report
+            record.msg = self.redact(str(record.msg))
+        if record.exc_info and record.exc_info[1] and not record.exc_text:
+            record.exc_text = logging.Formatter().formatException(record.exc_info)
+        if record.exc_text:
+            record.exc_text = self.redact(record.exc_text)
+        if record.stack_info:
+            record.stack_info = self.redact(record.stack_info)
+        return record
+
+
 class JsonFormatter(logging.Formatter):
     """Format log records as single-line JSON objects.
 
@@ -87,7 +203,9 @@ class JsonFormatter(logging.Formatter):
             "logger": record.name,
             "message": record.getMessage(),
         }
-        if record.exc_info and record.exc_info[1]:
+        if record.exc_text:
+            payload["exception"] = record.exc_text
+        elif record.exc_info and record.exc_info[1]:
             payload["exception"] = self.formatException(record.exc_info)
         return json.dumps(payload, default=str)
 
@@ -137,7 +255,9 @@ class ColoredFormatter(logging.Formatter):
 
         # Build final line — skip the parent's getMessage (we handled it)
         line = f"{record.asctime} {record.levelname} {record.name}  {record.msg}"
-        if record.exc_info and record.exc_info[1]:
+        if record.exc_text:
+            line += "\n" + record.exc_text
+        elif record.exc_info and record.exc_info[1]:
             line += "\n" + self.formatException(record.exc_info)
         return line
 
@@ -185,6 +305,7 @@ def configure_logging(
     datefmt: str = _DEFAULT_DATEFMT,
     stream: Any | None = None,
     format_style: str = "text",
+    redact: bool | RedactionFilter = False,
 ) -> None:
     """Configure logging for all ``fireflyframework_agentic`` modules.
 
@@ -207,6 +328,8 @@ def configure_logging(
             ``"json"`` for structured JSON lines, or ``"colored"`` for
             ANSI-coloured terminal output (falls back to plain text when
             the stream is not a TTY).
+        redact: ``True`` to attach a default :class:`RedactionFilter` to
+            the handler, or a configured :class:`RedactionFilter` instance.
     """
     logger = logging.getLogger(_LOGGER_NAME)
 
@@ -223,6 +346,8 @@ def configure_logging(
         handler.setFormatter(ColoredFormatter(fmt, datefmt))
     else:
         handler.setFormatter(logging.Formatter(fmt, datefmt=datefmt))
+    if redact:
+        handler.addFilter(redact if isinstance(redact, RedactionFilter) else RedactionFilter())
     handler._firefly_managed = True  # type: ignore[attr-defined]
 
     resolved_level = level if isinstance(level, int) else getattr(logging, level.upper(), logging.INFO)
@@ -51,12 +51,13 @@ _PII_PATTERNS: dict[str, str] = {
 
 _SECRETS_PATTERNS: dict[str, str] = {
     "api_key_generic": r"(?i)\b(?:api[_-]?key|apikey)\s*[:=]\s*['\"]?[A-Za-z0-9_\-]{20,}",
-    "bearer_token": r"(?i)bearer\s+[A-Za-z0-9_\-\.]{20,}",
+    "bearer_token": r"(?i)bearer\s+[A-Za-z0-9_\-\.=]{20,}",
     "openai_key": r"\bsk-[A-Za-z0-9]{20,}\b",
     "anthropic_key": r"\bsk-ant-[A-Za-z0-9_\-]{20,}\b",
     "aws_access_key": r"\bAKIA[0-9A-Z]{16}\b",
     "aws_secret_key": r"(?i)aws[_-]?secret[_-]?access[_-]?key\s*[:=]\s*['\"]?[A-Za-z0-9/+=]{40}",
-    "private_key": r"-----BEGIN (?:RSA |EC |DSA )?PRIVATE KEY-----",
+    # Whole PEM block when the END line is present, else just the header
+    "private_key": r"-----BEGIN [A-Z ]*PRIVATE KEY-----(?:[\s\S]*?-----END [A-Z ]*PRIVATE KEY-----)?",
     "github_token": r"\bgh[ps]_[A-Za-z0-9_]{36,}\b",
     "password_in_text": r"(?i)password\s*[:=]\s*['\"]?[^\s'\"]{8,}",
 }
@@ -277,6 +277,68 @@ class TestLoggingMiddleware:
         assert "3" in suffix
 
 
+# -- Built-in: AuditMiddleware ----------------------------------------------
+
+
+class TestAuditMiddleware:
+    async def test_appends_digests_and_usage(self) -> None:
+        from types import SimpleNamespace
+
+        from fireflyframework_agentic.agents.builtin_middleware import AuditMiddleware
+        from fireflyframework_agentic.explainability.audit import AuditTrail, hash_content
+
+        trail = AuditTrail()
+        mw = AuditMiddleware(trail=trail)
+        ctx = MiddlewareContext(agent_name="auditor", prompt="secret prompt", model="openai:gpt-4o")
+        usage = SimpleNamespace(input_tokens=12, output_tokens=3, total_tokens=15)
+        result = SimpleNamespace(output="secret answer", usage=lambda: usage)
+
+        await mw.before_run(ctx)
+        assert await mw.after_run(ctx, result) is result
+
+        (entry,) = trail.entries
+        assert entry.actor == "auditor"
+        assert entry.action == "llm_call"
+        assert entry.resource == "openai:gpt-4o"
+        assert entry.detail["total_tokens"] == 15
+        assert entry.detail["prompt_sha256"] == hash_content("secret prompt")
+        assert entry.detail["output_sha256"] == hash_content("secret answer")
+        assert "secret" not in str(entry.detail)
+        assert trail.verify()
+
+    async def test_failed_stream_recorded_as_failure(self) -> None:
+        from fireflyframework_agentic.agents.builtin_middleware import AuditMiddleware
+        from fireflyframework_agentic.explainability.audit import AuditTrail
+
+        trail = AuditTrail()
+        mw = AuditMiddleware(trail=trail)
+        ctx = MiddlewareContext(agent_name="auditor", prompt="hi", method="run_stream")
+        await mw.before_run(ctx)
+        ctx.metadata["_stream_error"] = RuntimeError("connection reset")
+        await mw.after_run(ctx, object())
+
+        (entry,) = trail.entries
+        assert entry.outcome == "failure"
+        assert entry.detail["error"] == "RuntimeError"
+
+    async def test_records_agent_model_on_run(self) -> None:
+        from fireflyframework_agentic.agents import AuditMiddleware
+        from fireflyframework_agentic.explainability.audit import AuditTrail
+
+        trail = AuditTrail()
+        agent = FireflyAgent(
+            "mw-audit",
+            model="test",
+            middleware=[AuditMiddleware(trail=trail)],
+            auto_register=False,
+        )
+        await agent.run("hello")
+        assert len(trail) == 1
+        assert trail.entries[0].actor == "mw-audit"
+        assert trail.entries[0].resource == agent._model_identifier
+        assert trail.verify()
+
+
 # -- Auto-wiring default middleware -----------------------------------------
 
 
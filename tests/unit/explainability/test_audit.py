@@ -16,7 +16,12 @@
 
 from __future__ import annotations
 
-from fireflyframework_agentic.explainability.audit import AuditTrail
+import json
+
+import pytest
+from pydantic import ValidationError
+
+from fireflyframework_agentic.explainability.audit import AuditTrail, hash_content
 
 
 class TestAuditTrail:
@@ -37,3 +42,115 @@ class TestAuditTrail:
         trail.append(actor="a", action="act")
         entries = trail.entries
         assert len(entries) == 1
+
+    def test_entries_are_hash_chained(self):
+        trail = AuditTrail()
+        first = trail.append(actor="a", action="llm_call")
+        second = trail.append(actor="a", action="tool_execution")
+        assert first.previous_hash == ""
+        assert second.previous_hash == first.hash
+        assert trail.verify()
+
+    def test_verify_detects_tampering(self):
+        trail = AuditTrail()
+        trail.append(actor="a", action="llm_call", detail={"tokens": 10})
+        trail.append(actor="a", action="llm_call", detail={"tokens": 20})
+        trail._entries[0].detail["tokens"] = 1
+        assert not trail.verify()
+
+    def test_verify_detects_removal(self):
+        trail = AuditTrail()
+        for i in range(3):
+            trail.append(actor="a", action=f"act{i}")
+        del trail._entries[1]
+        assert not trail.verify()
+
+    def test_persisted_trail_reloads_and_continues_chain(self, tmp_path):
+        path = tmp_path / "audit.jsonl"
+        trail = AuditTrail(path)
+        last = trail.append(actor="a", action="llm_call", detail={"prompt_sha256": hash_content("hi")})
+        reopened = AuditTrail(path)
+        assert len(reopened) == 1
+        nxt = reopened.append(actor="a", action="llm_call")
+        assert nxt.previous_hash == last.hash
+        assert AuditTrail(path).verify()
+
+    def test_export_jsonl_and_csv(self):
+        trail = AuditTrail()
+        trail.append(actor="a", action="llm_call", detail={"model": "gpt-4o"})
+        trail.append(actor="b", action="tool_execution")
+        lines = trail.export_jsonl().splitlines()
+        assert [json.loads(line)["actor"] for line in lines] == ["a", "b"]
+        csv_text = trail.export_csv()
+        assert csv_text.splitlines()[0].startswith("timestamp,actor,action")
+        assert "gpt-4o" in csv_text
+
+    def test_non_serialisable_detail_is_stringified(self, tmp_path):
+        path = tmp_path / "audit.jsonl"
+        trail = AuditTrail(path)
+        entry = trail.append(actor="a", action="act", detail={"o": object()})
+        assert isinstance(entry.detail["o"], str)
+        assert trail.verify()
+        assert AuditTrail(path).verify()
+
+    def test_non_finite_float_detail_verifies_after_reload(self, tmp_path):
+        path = tmp_path / "audit.jsonl"
+        trail = AuditTrail(path)
+        trail.append(actor="a", action="act", detail={"score": float("nan"), "cost": float("inf")})
+        assert trail.verify()
+        assert AuditTrail(path).verify()
+
+    def test_returned_entries_cannot_break_the_chain(self):
+        trail = AuditTrail()
+        entry = trail.append(actor="a", action="act", detail={"tokens": 10})
+        entry.detail["tokens"] = 1
+        trail.entries[0].detail["tokens"] = 2
+        assert trail.entries[0].detail["tokens"] == 10
+        assert trail.verify()
+        with pytest.raises(ValidationError):
+            entry.actor = "b"
+
+    def test_truncated_last_line_is_discarded(self, tmp_path):
+        path = tmp_path / "audit.jsonl"
+        trail = AuditTrail(path)
+        first = trail.append(actor="a", action="act0")
+        trail.append(actor="a", action="act1")
+        path.write_bytes(path.read_bytes()[:-20])
+        damaged = path.read_bytes()
+        reopened = AuditTrail(path)
+        assert len(reopened) == 1
+        assert reopened.verify()
+        assert path.read_bytes() == damaged  # loading leaves the evidence as is
+        nxt = reopened.append(actor="a", action="act2")
+        assert nxt.previous_hash == first.hash
+        assert AuditTrail(path).verify()
+
+    def test_missing_final_newline_is_added_on_append(self, tmp_path):
+        path = tmp_path / "audit.jsonl"
+        AuditTrail(path).append(actor="a", action="act0")
+        path.write_bytes(path.read_bytes().rstrip(b"\n"))
+        reopened = AuditTrail(path)
+        reopened.append(actor="a", action="act1")
+        reloaded = AuditTrail(path)
+        assert len(reloaded) == 2
+        assert reloaded.verify()
+
+    def test_corrupt_line_raises(self, tmp_path):
+        path = tmp_path / "audit.jsonl"
+        trail = AuditTrail(path)
+        trail.append(actor="a", action="act0")
+        trail.append(actor="a", action="act1")
+        lines = path.read_text().splitlines()
+        path.write_text(f"{lines[0][:-5]}\n{lines[1]}\n")
+        with pytest.raises(ValueError, match="line 1"):
+            AuditTrail(path)
+
+    def test_expected_head_detects_tail_truncation(self):
+        trail = AuditTrail()
+        for i in range(3):
+            trail.append(actor="a", action=f"act{i}")
+        head = trail.head_hash
+        assert trail.verify(expected_head=head)
+        del trail._entries[-1]
+        assert trail.verify()
+        assert not trail.verify(expected_head=head)
@@ -0,0 +1,106 @@
+# Copyright 2026 Firefly Software Solutions Inc
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+"""Tests for eval report badge and HTML publishing."""
+
+from __future__ import annotations
+
+import json
+import threading
+from collections.abc import Iterator
+from http.server import BaseHTTPRequestHandler, HTTPServer
+
+import pytest
+
+from fireflyframework_agentic.lab.evaluator import EvalReport, EvalResult
+from fireflyframework_agentic.lab.publishing import EvalReportPublisher
+
+
+def _report(*scores: float) -> EvalReport:
+    results = [EvalResult(input=f"q{i}", expected="a", actual="a", score=s) for i, s in enumerate(scores)]
+    avg = sum(scores) / len(scores) if scores else 0.0
+    return EvalReport(agent_name="bot", total_cases=len(results), avg_score=avg, results=results)
+
+
+@pytest.fixture
+def endpoint() -> Iterator[tuple[str, list[dict], list[int]]]:
+    """Local HTTP server recording POSTed JSON bodies; status is configurable."""
+    received: list[dict] = []
+    status = [201]
+
+    class Handler(BaseHTTPRequestHandler):
+        def do_POST(self) -> None:  # noqa: N802
+            length = int(self.headers["Content-Length"])
+            received.append(json.loads(self.rfile.read(length)))
+            self.send_response(status[0])
+            self.end_headers()
+
+        def log_message(self, *args: object) -> None:
+            pass
+
+    server = HTTPServer(("127.0.0.1", 0), Handler)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield f"http://127.0.0.1:{server.server_port}/upload", received, status
+    server.shutdown()
+    server.server_close()
+
+
+class TestEvalReportPublisher:
+    def test_badge_pass_rate_and_colour(self):
+        badge = EvalReportPublisher().badge(_report(1.0, 1.0, 1.0, 0.0))
+        assert badge["schemaVersion"] == 1
+        assert badge["message"] == "75% pass"
+        assert badge["color"] == "green"
+        nearly_perfect = EvalReportPublisher().badge(_report(*[1.0] * 199, 0.0))
+        assert nearly_perfect["message"] == "99% pass"
+
+    def test_colors_in_any_order(self):
+        publisher = EvalReportPublisher(colors=[(0.0, "red"), (0.5, "yellow"), (0.9, "green")])
+        assert publisher.badge(_report(1.0, 1.0, 1.0, 0.0))["color"] == "yellow"
+        assert publisher.badge(_report(1.0))["color"] == "green"
+
+    def test_badge_empty_report(self):
+        badge = EvalReportPublisher(label="quality").badge(_report())
+        assert badge["label"] == "quality"
+        assert badge["message"] == "no data"
+
+    def test_html_escapes_content(self):
+        report = _report(1.0)
+        report.results[0].input = "<script>"
+        page = EvalReportPublisher().render_html(report)
+        assert "&lt;script&gt;" in page
+        assert "<script>" not in page
+
+    def test_write(self, tmp_path):
+        badge_path, html_path = EvalReportPublisher().write(_report(1.0), tmp_path / "out")
+        assert json.loads(badge_path.read_text())["color"] == "brightgreen"
+        assert "Evaluation report: bot" in html_path.read_text()
+
+    def test_empty_colors_are_respected(self):
+        badge = EvalReportPublisher(colors=[]).badge(_report(1.0))
+        assert badge["color"] == "red"
+
+    async def test_publish_posts_payload(self, endpoint):
+        url, received, _ = endpoint
+        status = await EvalReportPublisher().publish(_report(1.0, 0.0), url)
+        assert status == 201
+        assert received[0]["badge"]["message"] == "50% pass"
+        assert received[0]["report"]["agent_name"] == "bot"
+        assert "<html" in received[0]["html"]
+
+    async def test_publish_returns_error_status(self, endpoint):
+        url, _, status = endpoint
+        status[0] = 500
+        assert await EvalReportPublisher().publish(_report(1.0), url) == 500